@@ -0,0 +1,210 @@
+//! A fluent, typed alternative to hand-assembling raw [`Arg`]s.
+
+use crate::{Arg, Result, YoutubeDL};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// An audio format accepted by youtube-dl's `--audio-format`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AudioFormat {
+    Best,
+    Aac,
+    Flac,
+    Mp3,
+    M4a,
+    Opus,
+    Vorbis,
+    Wav,
+}
+
+impl AudioFormat {
+    fn as_str(self) -> &'static str {
+        match self {
+            AudioFormat::Best => "best",
+            AudioFormat::Aac => "aac",
+            AudioFormat::Flac => "flac",
+            AudioFormat::Mp3 => "mp3",
+            AudioFormat::M4a => "m4a",
+            AudioFormat::Opus => "opus",
+            AudioFormat::Vorbis => "vorbis",
+            AudioFormat::Wav => "wav",
+        }
+    }
+}
+
+/// Builds a [`YoutubeDL`] job through typed setters for the most common youtube-dl
+/// options, instead of hand-written [`Arg`]s.
+///
+/// # Example
+///
+/// ```no_run
+/// use ytd_rs::builder::YoutubeDLBuilder;
+/// use std::path::PathBuf;
+///
+/// let ytd = YoutubeDLBuilder::new(
+///     PathBuf::from("./path/to/download/directory"),
+///     "https://www.youtube.com/watch?v=uTO0KnDsVH0",
+/// )
+/// .format("best")
+/// .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct YoutubeDLBuilder {
+    path: PathBuf,
+    links: Vec<String>,
+    args: Vec<Arg>,
+}
+
+impl YoutubeDLBuilder {
+    /// Starts a builder for a single link.
+    pub fn new(dl_path: PathBuf, link: &str) -> YoutubeDLBuilder {
+        YoutubeDLBuilder::new_multiple_links(dl_path, vec![link.to_string()])
+    }
+
+    /// Starts a builder for multiple links sharing the same options.
+    pub fn new_multiple_links(dl_path: PathBuf, links: Vec<String>) -> YoutubeDLBuilder {
+        YoutubeDLBuilder {
+            path: dl_path,
+            links,
+            args: Vec::new(),
+        }
+    }
+
+    /// Sets the desired download format (`--format`).
+    pub fn format(mut self, format: &str) -> YoutubeDLBuilder {
+        self.args.push(Arg::new_with_arg("--format", format));
+        self
+    }
+
+    /// Sets the socket connection timeout (`--socket-timeout`), in whole seconds.
+    pub fn socket_timeout(mut self, timeout: Duration) -> YoutubeDLBuilder {
+        self.args.push(Arg::new_with_arg(
+            "--socket-timeout",
+            &timeout.as_secs().to_string(),
+        ));
+        self
+    }
+
+    /// Sets the output filename template (`--output`).
+    pub fn output_template(mut self, template: &str) -> YoutubeDLBuilder {
+        self.args.push(Arg::new_with_arg("--output", template));
+        self
+    }
+
+    /// Reads cookies from a Netscape-format file (`--cookies`).
+    pub fn cookies(mut self, cookies: PathBuf) -> YoutubeDLBuilder {
+        self.args
+            .push(Arg::new_with_arg("--cookies", &cookies.to_string_lossy()));
+        self
+    }
+
+    /// Extracts the audio track in the given format (`--extract-audio --audio-format`).
+    pub fn extract_audio(mut self, format: AudioFormat) -> YoutubeDLBuilder {
+        self.args.push(Arg::new("--extract-audio"));
+        self.args
+            .push(Arg::new_with_arg("--audio-format", format.as_str()));
+        self
+    }
+
+    /// Restricts a playlist download to the given items (`--playlist-items`).
+    pub fn playlist_items(mut self, items: &str) -> YoutubeDLBuilder {
+        self.args.push(Arg::new_with_arg("--playlist-items", items));
+        self
+    }
+
+    /// Limits the download speed (`--limit-rate`).
+    pub fn rate_limit(mut self, limit: &str) -> YoutubeDLBuilder {
+        self.args.push(Arg::new_with_arg("--limit-rate", limit));
+        self
+    }
+
+    /// Appends a raw [`Arg`] for options that don't have a typed setter.
+    pub fn extra_arg(mut self, arg: Arg) -> YoutubeDLBuilder {
+        self.args.push(arg);
+        self
+    }
+
+    /// Resolves the download path and links and produces a [`YoutubeDL`] job.
+    pub fn build(self) -> Result<YoutubeDL> {
+        YoutubeDL::new_multiple_links(&self.path, self.args, self.links)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder() -> YoutubeDLBuilder {
+        YoutubeDLBuilder::new(PathBuf::from("."), "https://example.com/video")
+    }
+
+    fn rendered_args(builder: YoutubeDLBuilder) -> Vec<String> {
+        builder.args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn format_pushes_format_flag() {
+        assert_eq!(rendered_args(builder().format("best")), vec!["--format best"]);
+    }
+
+    #[test]
+    fn socket_timeout_pushes_seconds() {
+        assert_eq!(
+            rendered_args(builder().socket_timeout(Duration::from_secs(30))),
+            vec!["--socket-timeout 30"]
+        );
+    }
+
+    #[test]
+    fn output_template_pushes_output_flag() {
+        assert_eq!(
+            rendered_args(builder().output_template("%(title)s.%(ext)s")),
+            vec!["--output %(title)s.%(ext)s"]
+        );
+    }
+
+    #[test]
+    fn cookies_pushes_cookies_flag() {
+        assert_eq!(
+            rendered_args(builder().cookies(PathBuf::from("/path/to/cookies.txt"))),
+            vec!["--cookies /path/to/cookies.txt"]
+        );
+    }
+
+    #[test]
+    fn extract_audio_pushes_extract_and_format_flags() {
+        assert_eq!(
+            rendered_args(builder().extract_audio(AudioFormat::Mp3)),
+            vec!["--extract-audio", "--audio-format mp3"]
+        );
+    }
+
+    #[test]
+    fn playlist_items_pushes_playlist_items_flag() {
+        assert_eq!(
+            rendered_args(builder().playlist_items("1-3")),
+            vec!["--playlist-items 1-3"]
+        );
+    }
+
+    #[test]
+    fn rate_limit_pushes_limit_rate_flag() {
+        assert_eq!(rendered_args(builder().rate_limit("50K")), vec!["--limit-rate 50K"]);
+    }
+
+    #[test]
+    fn extra_arg_pushes_the_raw_arg_unchanged() {
+        assert_eq!(
+            rendered_args(builder().extra_arg(Arg::new("--quiet"))),
+            vec!["--quiet"]
+        );
+    }
+
+    #[test]
+    fn setters_chain_and_accumulate_args_in_order() {
+        assert_eq!(
+            rendered_args(builder().format("best").rate_limit("1M")),
+            vec!["--format best", "--limit-rate 1M"]
+        );
+    }
+}
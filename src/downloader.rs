@@ -0,0 +1,47 @@
+//! Fetches a self-managed copy of the yt-dlp binary so callers aren't required to have
+//! it installed on `PATH` already.
+
+use crate::error::YoutubeDLError;
+use crate::Result;
+use std::io::copy;
+use std::path::{Path, PathBuf};
+
+/// The yt-dlp release asset to fetch for the current OS/arch, served from the
+/// `latest` GitHub release alias.
+#[cfg(target_os = "windows")]
+const ASSET_NAME: &str = "yt-dlp.exe";
+#[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+const ASSET_NAME: &str = "yt-dlp_macos";
+#[cfg(all(target_os = "macos", not(target_arch = "aarch64")))]
+const ASSET_NAME: &str = "yt-dlp_macos";
+#[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+const ASSET_NAME: &str = "yt-dlp_linux_aarch64";
+#[cfg(all(target_os = "linux", not(target_arch = "aarch64")))]
+const ASSET_NAME: &str = "yt-dlp_linux";
+
+const RELEASE_URL: &str = "https://github.com/yt-dlp/yt-dlp/releases/latest/download";
+
+/// Downloads the latest yt-dlp release asset for the current OS/arch into `dest_dir`,
+/// marks it executable on Unix, and returns the path to the downloaded binary.
+pub fn download_yt_dlp(dest_dir: &Path) -> Result<PathBuf> {
+    std::fs::create_dir_all(dest_dir)?;
+    let dest = dest_dir.join(ASSET_NAME);
+
+    let url = format!("{RELEASE_URL}/{ASSET_NAME}");
+    let mut response = reqwest::blocking::get(&url)
+        .and_then(|r| r.error_for_status())
+        .map_err(YoutubeDLError::DownloadError)?;
+
+    let mut file = std::fs::File::create(&dest)?;
+    copy(&mut response, &mut file)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = std::fs::metadata(&dest)?.permissions();
+        permissions.set_mode(0o755);
+        std::fs::set_permissions(&dest, permissions)?;
+    }
+
+    Ok(dest)
+}
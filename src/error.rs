@@ -8,4 +8,12 @@ pub enum YoutubeDLError {
     UTF8Error(#[from] std::string::FromUtf8Error),
     #[error("youtube-dl exited with: {0}")]
     Failure(String),
+    #[error("failed to parse youtube-dl json output")]
+    JSONError(#[from] serde_json::Error),
+    #[cfg(feature = "tokio")]
+    #[error("failed to join async youtube-dl task")]
+    JoinError(#[from] tokio::task::JoinError),
+    #[cfg(feature = "downloader-rustls-tls")]
+    #[error("failed to download yt-dlp")]
+    DownloadError(#[from] reqwest::Error),
 }
@@ -24,10 +24,14 @@
 //! ```
 
 use error::YoutubeDLError;
+use progress::Progress;
 use std::{
     fmt,
+    io::{BufRead, BufReader, Read},
     process::{Output, Stdio},
 };
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
 use std::{
     fmt::{Display, Formatter},
     fs::{canonicalize, create_dir_all},
@@ -35,7 +39,13 @@ use std::{
 };
 use std::{path::Path, process::Command};
 
+pub mod builder;
+#[cfg(feature = "downloader-rustls-tls")]
+pub mod downloader;
 pub mod error;
+pub mod metadata;
+pub mod progress;
+pub mod watcher;
 type Result<T> = std::result::Result<T, YoutubeDLError>;
 
 const YOUTUBE_DL_COMMAND: &str = if cfg!(feature = "youtube-dlc") {
@@ -46,6 +56,11 @@ const YOUTUBE_DL_COMMAND: &str = if cfg!(feature = "youtube-dlc") {
     "youtube-dl"
 };
 
+/// Passed to `creation_flags` so spawning youtube-dl on Windows doesn't pop up a
+/// console window for every download.
+#[cfg(target_os = "windows")]
+const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
 /// A structure that represents an argument of a youtube-dl command.
 ///
 /// There are two different kinds of Arg:
@@ -64,7 +79,7 @@ const YOUTUBE_DL_COMMAND: &str = if cfg!(feature = "youtube-dlc") {
 /// // cookie file
 /// let input_arg = Arg::new_with_arg("--cookie", "/path/to/cookie");
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Arg {
     arg: String,
     input: Option<String>,
@@ -104,6 +119,8 @@ pub struct YoutubeDL {
     path: PathBuf,
     links: Vec<String>,
     args: Vec<Arg>,
+    bin: String,
+    hide_console: bool,
 }
 
 ///
@@ -136,6 +153,17 @@ impl YoutubeDLResult {
     pub fn output_dir(&self) -> &PathBuf {
         &self.path
     }
+
+    /// Parses the raw [`output`](Self::output) of a run started with `-J`/`--dump-json`
+    /// into a typed [`metadata::YoutubeDLOutput`].
+    ///
+    /// Accepts either a single top-level JSON object (a video, or a playlist with an
+    /// `entries` array) or newline-delimited per-video objects, which yt-dlp emits for
+    /// playlists when `--dump-json` is used without `-J`; the latter is collapsed into
+    /// a single [`metadata::Playlist`].
+    pub fn parse_json(&self) -> Result<metadata::YoutubeDLOutput> {
+        metadata::parse(&self.output)
+    }
 }
 
 impl YoutubeDL {
@@ -168,16 +196,121 @@ impl YoutubeDL {
 
         // absolute path
         let path = canonicalize(dl_path)?;
-        Ok(YoutubeDL { path, links, args })
+        Ok(YoutubeDL {
+            path,
+            links,
+            args,
+            bin: YOUTUBE_DL_COMMAND.to_string(),
+            hide_console: true,
+        })
     }
 
     pub fn new(dl_path: &PathBuf, args: Vec<Arg>, link: &str) -> Result<YoutubeDL> {
         YoutubeDL::new_multiple_links(dl_path, args, vec![link.to_string()])
     }
 
+    /// Uses a specific youtube-dl/yt-dlp binary (e.g. one fetched by a self-managed
+    /// bootstrap step) instead of resolving the hardcoded command name on `PATH`.
+    pub fn with_binary(mut self, binary: PathBuf) -> YoutubeDL {
+        self.bin = binary.to_string_lossy().into_owned();
+        self
+    }
+
+    /// Lets the child process's console window show on Windows (default: hidden).
+    /// No-op on other platforms.
+    pub fn show_console(mut self) -> YoutubeDL {
+        self.hide_console = false;
+        self
+    }
+
     /// Starts the download and returns when finished the result as [`YoutubeDLResult`].
     pub fn download(&self) -> Result<YoutubeDLResult> {
         let output = self.spawn_youtube_dl()?;
+        self.finish(output)
+    }
+
+    /// Starts the download on the `tokio` runtime and resolves when finished, without
+    /// blocking the calling thread while the child process runs.
+    #[cfg(feature = "tokio")]
+    pub async fn download_async(&self) -> Result<YoutubeDLResult> {
+        let mut cmd = tokio::process::Command::new(&self.bin);
+        cmd.current_dir(&self.path)
+            .env("LC_ALL", "en_US.UTF-8")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        #[cfg(target_os = "windows")]
+        if self.hide_console {
+            cmd.creation_flags(CREATE_NO_WINDOW);
+        }
+        push_args(&mut cmd, &self.args, &self.links);
+
+        let child = cmd.spawn()?;
+        let output = tokio::spawn(async move { child.wait_with_output().await })
+            .await
+            .map_err(YoutubeDLError::JoinError)??;
+
+        self.finish(output)
+    }
+
+    /// Starts the download and calls `callback` with a [`Progress`] update for every
+    /// `[download]` progress or fragment line youtube-dl prints, instead of waiting for
+    /// it to finish. Other `[download]` lines (e.g. `Destination:`) produce no update.
+    ///
+    /// Injects `--newline` so youtube-dl flushes one progress line at a time rather
+    /// than redrawing a single line in place.
+    pub fn download_with_progress<F: FnMut(Progress)>(&self, mut callback: F) -> Result<YoutubeDLResult> {
+        let mut cmd = Command::new(&self.bin);
+        cmd.current_dir(&self.path)
+            .env("LC_ALL", "en_US.UTF-8")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        #[cfg(target_os = "windows")]
+        if self.hide_console {
+            cmd.creation_flags(CREATE_NO_WINDOW);
+        }
+        push_args(&mut cmd, &self.args, &self.links);
+        cmd.arg("--newline");
+
+        let mut child = cmd.spawn()?;
+        let stdout = child.stdout.take().expect("child stdout was piped");
+        let mut stderr = child.stderr.take().expect("child stderr was piped");
+
+        // Drain stderr on its own thread while we read stdout below: youtube-dl can write
+        // enough warnings/retries to stderr to fill the pipe buffer, and reading only one
+        // stream at a time risks the classic `wait_with_output`-shaped deadlock.
+        let stderr_reader = std::thread::spawn(move || -> std::io::Result<Vec<u8>> {
+            let mut buf = Vec::new();
+            stderr.read_to_end(&mut buf)?;
+            Ok(buf)
+        });
+
+        let mut captured = String::new();
+        for line in BufReader::new(stdout).lines() {
+            let line = line?;
+            if let Some(progress) = progress::parse(&line) {
+                callback(progress);
+            }
+            captured.push_str(&line);
+            captured.push('\n');
+        }
+
+        let status = child.wait()?;
+        let stderr = stderr_reader
+            .join()
+            .expect("stderr reader thread panicked")?;
+
+        if !status.success() {
+            return Err(YoutubeDLError::Failure(String::from_utf8(stderr)?));
+        }
+
+        let mut result = YoutubeDLResult::new(&self.path);
+        result.output = captured;
+        Ok(result)
+    }
+
+    /// Turns the raw [`Output`] of a finished child process into a [`YoutubeDLResult`],
+    /// or a [`YoutubeDLError::Failure`] if it didn't exit successfully.
+    fn finish(&self, output: Output) -> Result<YoutubeDLResult> {
         let mut result = YoutubeDLResult::new(&self.path);
 
         if !output.status.success() {
@@ -189,28 +322,54 @@ impl YoutubeDL {
     }
 
     fn spawn_youtube_dl(&self) -> Result<Output> {
-        let mut cmd = Command::new(YOUTUBE_DL_COMMAND);
+        let mut cmd = Command::new(&self.bin);
         cmd.current_dir(&self.path)
             .env("LC_ALL", "en_US.UTF-8")
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
-
-        for arg in self.args.iter() {
-            match &arg.input {
-                Some(input) => cmd.arg(&arg.arg).arg(input),
-                None => cmd.arg(&arg.arg),
-            };
-        }
-
-        for link in self.links.iter() {
-            cmd.arg(&link);
+        #[cfg(target_os = "windows")]
+        if self.hide_console {
+            cmd.creation_flags(CREATE_NO_WINDOW);
         }
+        push_args(&mut cmd, &self.args, &self.links);
 
         let pr = cmd.spawn()?;
         Ok(pr.wait_with_output()?)
     }
 }
 
+/// Pushes the job's [`Arg`]s followed by its links onto a command, shared between the
+/// synchronous [`std::process::Command`] and (with the `tokio` feature) [`tokio::process::Command`].
+fn push_args<C: PushArg>(cmd: &mut C, args: &[Arg], links: &[String]) {
+    for arg in args.iter() {
+        match &arg.input {
+            Some(input) => cmd.push_arg(&arg.arg).push_arg(input),
+            None => cmd.push_arg(&arg.arg),
+        };
+    }
+
+    for link in links.iter() {
+        cmd.push_arg(link);
+    }
+}
+
+trait PushArg {
+    fn push_arg(&mut self, arg: &str) -> &mut Self;
+}
+
+impl PushArg for Command {
+    fn push_arg(&mut self, arg: &str) -> &mut Self {
+        self.arg(arg)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl PushArg for tokio::process::Command {
+    fn push_arg(&mut self, arg: &str) -> &mut Self {
+        self.arg(arg)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Arg, YoutubeDL};
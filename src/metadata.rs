@@ -0,0 +1,175 @@
+//! Typed metadata parsing for `--dump-json`/`-J` output.
+
+use crate::error::YoutubeDLError;
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The parsed result of a metadata run.
+///
+/// youtube-dl/yt-dlp report a single JSON object for one video, a single
+/// JSON object with an `entries` array when `-J`/`--dump-json` is combined
+/// with a playlist, or one JSON object per line (newline-delimited) when a
+/// playlist is dumped without `-J`. [`crate::YoutubeDLResult::parse_json`] collapses
+/// all three shapes into this enum.
+///
+/// Deliberately not `Deserialize`: `Video`'s fields are all optional plus a
+/// catch-all [`extra`](Video::extra), so it matches any JSON object, and an
+/// untagged `#[serde(untagged)]` deserialization would always resolve to
+/// `SingleVideo` even for a playlist. [`parse`] is the only correct way to
+/// produce a `YoutubeDLOutput`; it dispatches on the presence of `entries`
+/// itself instead of relying on untagged enum resolution.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum YoutubeDLOutput {
+    SingleVideo(Box<Video>),
+    Playlist(Box<Playlist>),
+}
+
+/// A single video entry, covering the fields callers reach for most often.
+///
+/// Anything yt-dlp emits that isn't modeled explicitly is preserved in
+/// [`extra`](Video::extra) so new fields don't break deserialization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Video {
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub webpage_url: Option<String>,
+    pub duration: Option<f64>,
+    pub uploader: Option<String>,
+    #[serde(default)]
+    pub formats: Vec<Format>,
+    #[serde(default)]
+    pub thumbnails: Vec<Thumbnail>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A playlist, either dumped as a single object with an `entries` array or
+/// collapsed from newline-delimited per-video objects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Playlist {
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub webpage_url: Option<String>,
+    pub uploader: Option<String>,
+    #[serde(default)]
+    pub entries: Vec<Video>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A single downloadable format of a [`Video`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Format {
+    pub format_id: Option<String>,
+    pub ext: Option<String>,
+    pub url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A single thumbnail of a [`Video`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Thumbnail {
+    pub url: Option<String>,
+    pub width: Option<i64>,
+    pub height: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Parses `output` as either a single `--dump-json` object or newline-delimited
+/// per-video objects, collapsing the latter into a [`Playlist`].
+pub(crate) fn parse(output: &str) -> Result<YoutubeDLOutput> {
+    let lines: Vec<&str> = output.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+
+    if lines.is_empty() {
+        return Err(YoutubeDLError::JSONError(
+            serde_json::from_str::<Value>("").unwrap_err(),
+        ));
+    }
+
+    if lines.len() == 1 {
+        let value: Value = serde_json::from_str(lines[0])?;
+        if value.get("entries").is_some() {
+            return Ok(YoutubeDLOutput::Playlist(Box::new(serde_json::from_value(
+                value,
+            )?)));
+        }
+        return Ok(YoutubeDLOutput::SingleVideo(Box::new(serde_json::from_value(
+            value,
+        )?)));
+    }
+
+    let entries = lines
+        .into_iter()
+        .map(serde_json::from_str)
+        .collect::<std::result::Result<Vec<Video>, _>>()?;
+
+    Ok(YoutubeDLOutput::Playlist(Box::new(Playlist {
+        id: None,
+        title: None,
+        webpage_url: None,
+        uploader: None,
+        entries,
+        extra: Map::new(),
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_single_video() {
+        let output = r#"{"id": "abc123", "title": "A video", "webpage_url": "https://example.com/abc123"}"#;
+        match parse(output).unwrap() {
+            YoutubeDLOutput::SingleVideo(video) => {
+                assert_eq!(video.id.as_deref(), Some("abc123"));
+                assert_eq!(video.title.as_deref(), Some("A video"));
+            }
+            YoutubeDLOutput::Playlist(_) => panic!("expected a single video"),
+        }
+    }
+
+    #[test]
+    fn parses_single_object_playlist_with_entries() {
+        let output = r#"{"id": "playlist1", "title": "A playlist", "entries": [{"id": "vid1", "title": "First"}, {"id": "vid2", "title": "Second"}]}"#;
+        match parse(output).unwrap() {
+            YoutubeDLOutput::Playlist(playlist) => {
+                assert_eq!(playlist.id.as_deref(), Some("playlist1"));
+                assert_eq!(playlist.entries.len(), 2);
+                assert_eq!(playlist.entries[0].id.as_deref(), Some("vid1"));
+            }
+            YoutubeDLOutput::SingleVideo(_) => panic!("expected a playlist"),
+        }
+    }
+
+    #[test]
+    fn collapses_newline_delimited_videos_into_a_playlist() {
+        let output = "{\"id\": \"vid1\", \"title\": \"First\"}\n{\"id\": \"vid2\", \"title\": \"Second\"}\n";
+        match parse(output).unwrap() {
+            YoutubeDLOutput::Playlist(playlist) => {
+                assert!(playlist.id.is_none());
+                assert_eq!(playlist.entries.len(), 2);
+                assert_eq!(playlist.entries[1].id.as_deref(), Some("vid2"));
+            }
+            YoutubeDLOutput::SingleVideo(_) => panic!("expected a collapsed playlist"),
+        }
+    }
+
+    #[test]
+    fn keeps_unknown_fields_in_extra() {
+        let output = r#"{"id": "abc123", "some_new_field": "value"}"#;
+        match parse(output).unwrap() {
+            YoutubeDLOutput::SingleVideo(video) => {
+                assert_eq!(
+                    video.extra.get("some_new_field").and_then(Value::as_str),
+                    Some("value")
+                );
+            }
+            YoutubeDLOutput::Playlist(_) => panic!("expected a single video"),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Streaming progress parsed from youtube-dl's `--newline` output.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// A single youtube-dl download progress update.
+///
+/// Parsed from lines like
+/// `[download]  42.3% of 10.00MiB at 1.20MiB/s ETA 00:05`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Progress {
+    pub percent: f32,
+    pub total_bytes: Option<u64>,
+    pub speed: Option<String>,
+    pub eta: Option<String>,
+    pub fragment: Option<(u32, u32)>,
+}
+
+static DOWNLOAD_LINE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?x)
+        \[download\]\s+
+        (?P<percent>\d+(?:\.\d+)?)%\s+
+        of\s+(?P<size>[\d.]+)(?P<unit>\w+)\s+
+        (?:
+            at\s+(?P<speed>[\d.]+\w+/s|Unknown\s+speed)\s+ETA\s+(?P<eta>[\d:]+)
+            |
+            in\s+[\d:]+
+        )
+        ",
+    )
+    .unwrap()
+});
+
+static FRAGMENT_LINE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Downloading fragment (?P<cur>\d+) of (?P<total>\d+)").unwrap());
+
+/// Converts a human-readable size like `10.00MiB` into a byte count.
+fn bytes_from_human(size: &str, unit: &str) -> Option<u64> {
+    let size: f64 = size.parse().ok()?;
+    let multiplier = match unit {
+        "B" => 1u64,
+        "KiB" => 1024,
+        "MiB" => 1024 * 1024,
+        "GiB" => 1024 * 1024 * 1024,
+        "TiB" => 1024 * 1024 * 1024 * 1024,
+        _ => return None,
+    };
+    Some((size * multiplier as f64) as u64)
+}
+
+/// Parses a single line of `--newline` output into a [`Progress`] update, if it is one.
+pub(crate) fn parse(line: &str) -> Option<Progress> {
+    if let Some(caps) = DOWNLOAD_LINE.captures(line) {
+        let percent: f32 = caps.name("percent")?.as_str().parse().ok()?;
+        let total_bytes = bytes_from_human(&caps["size"], &caps["unit"]);
+        let speed = caps.name("speed").map(|m| m.as_str().to_string());
+        let eta = caps.name("eta").map(|m| m.as_str().to_string());
+
+        return Some(Progress {
+            percent,
+            total_bytes,
+            speed,
+            eta,
+            fragment: None,
+        });
+    }
+
+    if let Some(caps) = FRAGMENT_LINE.captures(line) {
+        let cur: u32 = caps["cur"].parse().ok()?;
+        let total: u32 = caps["total"].parse().ok()?;
+
+        return Some(Progress {
+            percent: 0.0,
+            total_bytes: None,
+            speed: None,
+            eta: None,
+            fragment: Some((cur, total)),
+        });
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_download_line() {
+        let progress = parse("[download]  42.3% of 10.00MiB at 1.20MiB/s ETA 00:05").unwrap();
+        assert_eq!(progress.percent, 42.3);
+        assert_eq!(progress.total_bytes, Some(10 * 1024 * 1024));
+        assert_eq!(progress.speed.as_deref(), Some("1.20MiB/s"));
+        assert_eq!(progress.eta.as_deref(), Some("00:05"));
+        assert_eq!(progress.fragment, None);
+    }
+
+    #[test]
+    fn parses_finished_download_line() {
+        let progress = parse("[download] 100% of 10.00MiB in 00:08").unwrap();
+        assert_eq!(progress.percent, 100.0);
+        assert_eq!(progress.total_bytes, Some(10 * 1024 * 1024));
+        assert_eq!(progress.speed, None);
+        assert_eq!(progress.eta, None);
+        assert_eq!(progress.fragment, None);
+    }
+
+    #[test]
+    fn parses_fragment_line() {
+        let progress = parse("[download] Downloading fragment 3 of 20").unwrap();
+        assert_eq!(progress.fragment, Some((3, 20)));
+    }
+
+    #[test]
+    fn ignores_unrelated_lines() {
+        assert!(parse("[youtube] Extracting URL").is_none());
+    }
+}